@@ -0,0 +1,239 @@
+use crate::visit::{IntoEdgesDirected, IntoNeighborsDirected, IntoNodeIdentifiers, Visitable};
+use crate::visit::EdgeRef;
+use crate::Direction;
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+/// [Generic] Collect maximal runs of nodes joined by an alternating two-colored edge coloring.
+///
+/// Useful for merging chains of operations that alternate between two kinds
+/// of connections over a DAG, e.g. coalescing passes over a dependency
+/// graph. `edge_color` classifies each edge as `0`, `1`, or `None` (not
+/// part of any run); `is_run_node` selects which nodes are eligible to
+/// belong to a run at all.
+///
+/// Nodes are visited in topological order (the graph must have no cycles).
+/// A run starts at an eligible node with a single, unambiguous outgoing
+/// colored edge, and is extended through an eligible successor reached by
+/// that edge, alternating the color expected on each subsequent edge.
+/// Ambiguity (more than one same-colored edge on either end of the link),
+/// or a node failing `is_run_node`, closes the run it would otherwise have
+/// extended. Runs are emitted, in topological order, as soon as they can no
+/// longer be extended.
+///
+/// Returns all non-empty runs.
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::bicolor_runs::collect_bicolor_runs;
+///
+/// let mut graph = Graph::<(), usize>::new();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+///
+/// graph.add_edge(a, b, 0);
+/// graph.add_edge(b, c, 1);
+///
+/// let runs = collect_bicolor_runs(
+///     &graph,
+///     |_| true,
+///     |edge| Some(*edge.weight()),
+/// );
+/// assert_eq!(runs, vec![vec![a, b, c]]);
+/// ```
+pub fn collect_bicolor_runs<G, F, K>(
+    graph: G,
+    is_run_node: F,
+    edge_color: K,
+) -> Vec<Vec<G::NodeId>>
+where
+    G: IntoNodeIdentifiers + IntoNeighborsDirected + IntoEdgesDirected + Visitable,
+    G::NodeId: Eq + Hash + Copy,
+    F: Fn(G::NodeId) -> bool,
+    K: Fn(G::EdgeRef) -> Option<usize>,
+{
+    // A run currently being built, keyed by the edge color that must appear
+    // next (at its tail) to extend it further.
+    struct OpenRun<N> {
+        nodes: Vec<N>,
+        tail: N,
+    }
+
+    // Kahn's algorithm, hand-rolled rather than pulling in a separate
+    // topological iterator.
+    let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    let mut in_degree: HashMap<G::NodeId, usize> = nodes
+        .iter()
+        .map(|&n| (n, graph.neighbors_directed(n, Direction::Incoming).count()))
+        .collect();
+    let mut queue: VecDeque<G::NodeId> = nodes
+        .iter()
+        .copied()
+        .filter(|n| in_degree[n] == 0)
+        .collect();
+    let mut order = Vec::with_capacity(nodes.len());
+    while let Some(n) = queue.pop_front() {
+        order.push(n);
+        for succ in graph.neighbors_directed(n, Direction::Outgoing) {
+            let degree = in_degree.get_mut(&succ).expect("successor is a graph node");
+            *degree -= 1;
+            if *degree == 0 {
+                queue.push_back(succ);
+            }
+        }
+    }
+
+    let mut open: HashMap<usize, OpenRun<G::NodeId>> = HashMap::new();
+    let mut finished: Vec<Vec<G::NodeId>> = Vec::new();
+
+    for node in order {
+        let mut incoming: HashMap<usize, Vec<G::NodeId>> = HashMap::new();
+        for edge in graph.edges_directed(node, Direction::Incoming) {
+            if let Some(color) = edge_color(edge) {
+                incoming.entry(color).or_default().push(edge.source());
+            }
+        }
+
+        let mut outgoing_counts: HashMap<usize, usize> = HashMap::new();
+        for edge in graph.edges_directed(node, Direction::Outgoing) {
+            if let Some(color) = edge_color(edge) {
+                *outgoing_counts.entry(color).or_insert(0) += 1;
+            }
+        }
+        let has_unambiguous_outgoing = |color: usize| outgoing_counts.get(&color).copied().unwrap_or(0) == 1;
+
+        // Which color (if either) this node extended an open run through.
+        let mut extended_color: Option<usize> = None;
+
+        if is_run_node(node) {
+            // Iterate colors in a fixed order rather than the incoming
+            // HashMap's, so a convergence node with equally valid matches
+            // on both colors always picks the same one.
+            for color in [0usize, 1usize] {
+                let matches_tail = incoming.get(&color).map_or(false, |sources| {
+                    sources.len() == 1
+                        && open.get(&color).map_or(false, |run| run.tail == sources[0])
+                });
+                if !matches_tail {
+                    continue;
+                }
+
+                let mut run = open.remove(&color).expect("just confirmed this entry exists");
+                run.nodes.push(node);
+                run.tail = node;
+
+                let next_color = 1 - color;
+                if has_unambiguous_outgoing(next_color) {
+                    open.insert(next_color, run);
+                } else {
+                    finished.push(run.nodes);
+                }
+                extended_color = Some(color);
+                break;
+            }
+        }
+
+        // Close any run this node was positioned to extend through but
+        // didn't — whether it lost a tie to the other color at a
+        // convergence node, its matching incoming edge was ambiguous, or
+        // `node` itself is not a run node. Without this, a run a
+        // convergence node didn't pick would be left dangling in `open`
+        // forever, permanently blocking that color from being reused.
+        for color in [0usize, 1usize] {
+            if Some(color) == extended_color {
+                continue;
+            }
+            let should_close = incoming.get(&color).map_or(false, |sources| {
+                open.get(&color).map_or(false, |run| sources.contains(&run.tail))
+            });
+            if should_close {
+                let run = open.remove(&color).expect("just confirmed this entry exists");
+                finished.push(run.nodes);
+            }
+        }
+
+        if extended_color.is_none() && is_run_node(node) {
+            for color in [0usize, 1usize] {
+                let count = outgoing_counts.get(&color).copied().unwrap_or(0);
+                if count == 1 && !open.contains_key(&color) {
+                    open.insert(
+                        color,
+                        OpenRun {
+                            nodes: vec![node],
+                            tail: node,
+                        },
+                    );
+                }
+            }
+        }
+    }
+
+    for color in [0usize, 1usize] {
+        if let Some(run) = open.remove(&color) {
+            finished.push(run.nodes);
+        }
+    }
+
+    finished
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Graph;
+
+    #[test]
+    fn convergence_with_both_colors_is_deterministic() {
+        // `conv` has two single-source incoming edges, one of each color,
+        // each matching a different open run's tail. The result must not
+        // depend on iteration order over the incoming-edge map.
+        let mut graph = Graph::<(), usize>::new();
+        let a = graph.add_node(());
+        let b = graph.add_node(());
+        let conv = graph.add_node(());
+        graph.add_edge(a, conv, 0);
+        graph.add_edge(b, conv, 1);
+
+        let expected = collect_bicolor_runs(&graph, |_| true, |edge| Some(*edge.weight()));
+
+        for _ in 0..5 {
+            let runs = collect_bicolor_runs(&graph, |_| true, |edge| Some(*edge.weight()));
+            assert_eq!(runs, expected);
+        }
+
+        // Color 0 is preferred deterministically whenever a node has a
+        // valid extension available under both colors.
+        assert!(expected.contains(&vec![a, conv]));
+        assert!(expected.contains(&vec![b]));
+    }
+
+    #[test]
+    fn convergence_node_frees_the_color_it_does_not_extend() {
+        // `conv` matches both colors (as above), but a later, unrelated
+        // chain reuses color 1 — the same color `b`'s losing run held.
+        // That run must be closed when `conv` picks color 0, or the
+        // color-1 slot stays permanently squatted and `x`/`y` are dropped.
+        let mut graph = Graph::<(), usize>::new();
+        let a = graph.add_node(());
+        let b = graph.add_node(());
+        let conv = graph.add_node(());
+        let x = graph.add_node(());
+        let y = graph.add_node(());
+        graph.add_edge(a, conv, 0);
+        graph.add_edge(b, conv, 1);
+        graph.add_edge(conv, x, 2); // uncolored, just forces x/y after conv
+        graph.add_edge(x, y, 1);
+
+        let runs = collect_bicolor_runs(&graph, |_| true, |edge| match edge.weight() {
+            0 => Some(0),
+            1 => Some(1),
+            _ => None,
+        });
+
+        assert!(runs.contains(&vec![a, conv]));
+        assert!(runs.contains(&vec![b]));
+        assert!(runs.contains(&vec![x, y]));
+    }
+}
@@ -1,4 +1,5 @@
 use crate::visit::{IntoNeighbors, NodeCount, NodeIndexable, Visitable};
+use rand::Rng;
 use std::hash::Hash;
 use std::collections::HashMap;
 
@@ -145,3 +146,911 @@ where
 
     colors
 }
+
+/// [Generic] DSATUR (saturation degree) greedy graph coloring algorithm.
+///
+/// Compute a valid vertex coloring for an undirected graph by repeatedly
+/// coloring the uncolored vertex with the highest *saturation degree* (the
+/// number of distinct colors already used among its neighbors), breaking
+/// ties by highest uncolored-neighbor degree. Each chosen vertex receives
+/// the smallest positive color not already used by any of its neighbors.
+///
+/// Unlike [`wfc_color`], which starts from a palette of maximum degree + 1
+/// colors and always takes the first value left in a vertex's domain,
+/// DSATUR never backtracks or restarts: it always makes progress and tends
+/// to use noticeably fewer colors on real-world graphs. The implementation uses 1-based color
+/// numbering.
+///
+/// Returns a `HashMap` that maps node IDs to their assigned colors.
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::Undirected;
+/// use petgraph::algo::wfc_color::dsatur_color;
+///
+/// let mut graph = Graph::<(), (), Undirected>::new_undirected();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+///
+/// graph.extend_with_edges(&[
+///     (a, b),
+///     (b, c),
+///     (c, a),
+/// ]);
+///
+/// let coloring = dsatur_color(&graph);
+/// assert_ne!(coloring[&a], coloring[&b]); // Adjacent vertices have different colors
+/// ```
+pub fn dsatur_color<G>(graph: G) -> HashMap<G::NodeId, usize>
+where
+    G: IntoNeighbors + NodeCount + NodeIndexable,
+    G::NodeId: Eq + Hash + Copy,
+{
+    let mut colors: HashMap<G::NodeId, usize> = HashMap::new();
+    let total = graph.node_count();
+
+    while colors.len() < total {
+        // Pick the uncolored vertex with highest saturation degree, breaking
+        // ties by highest uncolored-neighbor degree.
+        let next = (0..graph.node_bound())
+            .map(|i| graph.from_index(i))
+            .filter(|v| !colors.contains_key(v))
+            .max_by_key(|&v| {
+                let mut used: Vec<usize> = graph
+                    .neighbors(v)
+                    .filter_map(|u| colors.get(&u).copied())
+                    .collect();
+                used.sort_unstable();
+                used.dedup();
+                let saturation = used.len();
+                let uncolored_degree = graph.neighbors(v).filter(|u| !colors.contains_key(u)).count();
+                (saturation, uncolored_degree)
+            })
+            .expect("node_count guarantees an uncolored vertex remains");
+
+        // Assign the smallest positive color not used by any neighbor.
+        let mut used: Vec<usize> = graph
+            .neighbors(next)
+            .filter_map(|u| colors.get(&u).copied())
+            .collect();
+        used.sort_unstable();
+
+        let mut color = 1;
+        for c in used {
+            if c == color {
+                color += 1;
+            } else if c > color {
+                break;
+            }
+        }
+
+        colors.insert(next, color);
+    }
+
+    colors
+}
+
+/// [Generic] Seedable Wave Function Collapse graph coloring with backtracking.
+///
+/// [`wfc_color`] always collapses to `domain[0]` and the lowest-index
+/// minimum-entropy node, which makes it fully deterministic despite the
+/// name, and it never revisits a collapse decision. This function performs
+/// genuine WFC: among all uncolored vertices of minimum non-zero entropy it
+/// picks one uniformly at random via `rng`, then picks one of its remaining
+/// domain values at random, weighted by how often each color already
+/// appears in the partial coloring so that established colors tend to be
+/// reused. If constraint propagation after a collapse empties some
+/// neighbor's domain, the decision is undone and retried with the next
+/// candidate value instead of restarting the whole search.
+///
+/// Returns `None` only once every value under `max_colors` has been
+/// exhausted for every collapse decision, i.e. no valid coloring exists
+/// with that many colors. Returns `Some` with a complete, conflict-free
+/// coloring otherwise. The implementation uses 1-based color numbering.
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::Undirected;
+/// use petgraph::algo::wfc_color::wfc_color_seeded;
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+///
+/// let mut graph = Graph::<(), (), Undirected>::new_undirected();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+///
+/// graph.extend_with_edges(&[
+///     (a, b),
+///     (b, c),
+///     (c, a),
+/// ]);
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let coloring = wfc_color_seeded(&graph, &mut rng, 3).unwrap();
+/// assert_ne!(coloring[&a], coloring[&b]); // Adjacent vertices have different colors
+/// ```
+pub fn wfc_color_seeded<G, R>(
+    graph: G,
+    rng: &mut R,
+    max_colors: usize,
+) -> Option<HashMap<G::NodeId, usize>>
+where
+    G: IntoNeighbors + NodeCount + NodeIndexable + Visitable,
+    G::NodeId: Eq + Hash + Copy,
+    R: Rng,
+{
+    // Constraint propagation that reports a contradiction (an emptied
+    // domain) instead of silently leaving the offending node uncolored.
+    fn propagate<G>(
+        graph: G,
+        start: G::NodeId,
+        colors: &mut HashMap<G::NodeId, usize>,
+        domains: &mut HashMap<G::NodeId, Vec<usize>>,
+        color_counts: &mut HashMap<usize, usize>,
+    ) -> bool
+    where
+        G: IntoNeighbors,
+        G::NodeId: Eq + Hash + Copy,
+    {
+        let mut stack = vec![start];
+        while let Some(u) = stack.pop() {
+            if let Some(&color) = colors.get(&u) {
+                for neighbor in graph.neighbors(u) {
+                    if colors.contains_key(&neighbor) {
+                        continue;
+                    }
+                    if let Some(domain) = domains.get_mut(&neighbor) {
+                        if domain.contains(&color) {
+                            domain.retain(|&c| c != color);
+                            match domain.len() {
+                                0 => return false,
+                                1 => {
+                                    colors.insert(neighbor, domain[0]);
+                                    *color_counts.entry(domain[0]).or_insert(0) += 1;
+                                    stack.push(neighbor);
+                                }
+                                _ => {}
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        true
+    }
+
+    // Draw domain values one at a time without replacement, weighted by how
+    // often each color is already in use (more-used colors are preferred).
+    fn weighted_order<R: Rng>(
+        domain: &[usize],
+        color_counts: &HashMap<usize, usize>,
+        rng: &mut R,
+    ) -> Vec<usize> {
+        let mut pool = domain.to_vec();
+        let mut order = Vec::with_capacity(pool.len());
+        while !pool.is_empty() {
+            let weights: Vec<usize> = pool
+                .iter()
+                .map(|c| color_counts.get(c).copied().unwrap_or(0) + 1)
+                .collect();
+            let total: usize = weights.iter().sum();
+            let mut pick = rng.gen_range(0..total);
+            let mut idx = pool.len() - 1;
+            for (i, &w) in weights.iter().enumerate() {
+                if pick < w {
+                    idx = i;
+                    break;
+                }
+                pick -= w;
+            }
+            order.push(pool.remove(idx));
+        }
+        // `order` is built most-preferred-first; reverse so the caller can
+        // draw the most preferred remaining value with a cheap `Vec::pop`.
+        order.reverse();
+        order
+    }
+
+    // A pending collapse decision: the state to restore to before retrying,
+    // the vertex that was collapsed, and the values still untried (most
+    // preferred last, so the next candidate is a `pop`).
+    struct Decision<N: Eq + Hash + Copy> {
+        colors: HashMap<N, usize>,
+        domains: HashMap<N, Vec<usize>>,
+        color_counts: HashMap<usize, usize>,
+        node: N,
+        remaining: Vec<usize>,
+    }
+
+    let nodes: Vec<G::NodeId> = (0..graph.node_bound()).map(|i| graph.from_index(i)).collect();
+
+    let mut colors: HashMap<G::NodeId, usize> = HashMap::new();
+    let mut domains: HashMap<G::NodeId, Vec<usize>> = nodes
+        .iter()
+        .map(|&n| (n, (1..=max_colors).collect()))
+        .collect();
+    let mut color_counts: HashMap<usize, usize> = HashMap::new();
+    let mut history: Vec<Decision<G::NodeId>> = Vec::new();
+
+    loop {
+        if colors.len() == nodes.len() {
+            return Some(colors);
+        }
+
+        let min_entropy = nodes
+            .iter()
+            .filter(|n| !colors.contains_key(n))
+            .map(|n| domains[n].len())
+            .min()
+            .unwrap_or(0);
+
+        let collapsed = if min_entropy > 0 {
+            let candidates: Vec<G::NodeId> = nodes
+                .iter()
+                .copied()
+                .filter(|n| !colors.contains_key(n) && domains[n].len() == min_entropy)
+                .collect();
+            let node = candidates[rng.gen_range(0..candidates.len())];
+
+            let mut remaining = weighted_order(&domains[&node], &color_counts, rng);
+            let chosen = remaining
+                .pop()
+                .expect("non-zero entropy means a domain value exists");
+
+            history.push(Decision {
+                colors: colors.clone(),
+                domains: domains.clone(),
+                color_counts: color_counts.clone(),
+                node,
+                remaining,
+            });
+
+            colors.insert(node, chosen);
+            *color_counts.entry(chosen).or_insert(0) += 1;
+            propagate(&graph, node, &mut colors, &mut domains, &mut color_counts)
+        } else {
+            false
+        };
+
+        if collapsed {
+            continue;
+        }
+
+        // Contradiction: unwind to the most recent decision that still has
+        // an untried value and collapse to that instead. Give up once the
+        // whole search space under `max_colors` is exhausted.
+        loop {
+            let decision = match history.pop() {
+                Some(d) => d,
+                None => return None,
+            };
+
+            let mut remaining = decision.remaining;
+            let next = match remaining.pop() {
+                Some(v) => v,
+                None => continue,
+            };
+
+            colors = decision.colors.clone();
+            domains = decision.domains.clone();
+            color_counts = decision.color_counts.clone();
+
+            colors.insert(decision.node, next);
+            *color_counts.entry(next).or_insert(0) += 1;
+            let retried = propagate(
+                &graph,
+                decision.node,
+                &mut colors,
+                &mut domains,
+                &mut color_counts,
+            );
+
+            history.push(Decision {
+                colors: decision.colors,
+                domains: decision.domains,
+                color_counts: decision.color_counts,
+                node: decision.node,
+                remaining,
+            });
+
+            if retried {
+                break;
+            }
+        }
+    }
+}
+
+/// Error returned by [`wfc_color_with_constraints`] when it cannot complete
+/// the fixed assignment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColoringError {
+    /// Either two adjacent vertices were pre-colored with the same color,
+    /// a fixed color exceeds the `max_colors` budget, or the single greedy
+    /// pass within that budget hit a contradiction. The pass does not
+    /// backtrack, so in the last case a valid completion may still exist.
+    Infeasible,
+}
+
+/// [Generic] Wave Function Collapse coloring seeded from a partial assignment.
+///
+/// Like [`wfc_color`], but some vertices arrive already bound to specific
+/// colors via `fixed` — e.g. items already committed to registers/map
+/// regions that the rest of the coloring must respect rather than disturb.
+/// Fixed vertices keep a singleton domain throughout, and constraint
+/// propagation runs from all of them before the usual collapse loop fills
+/// in the rest.
+///
+/// Each uncolored vertex of smallest domain is collapsed to the first value
+/// left in its domain; decisions are never revisited. If `max_colors` is
+/// `Some`, the palette is never grown to escape a contradiction; the
+/// function instead returns `Err(ColoringError::Infeasible)`, which also
+/// covers two adjacent fixed vertices sharing a color. Because there is no
+/// backtracking, this error does not prove that no completion exists. If
+/// `max_colors` is `None`, the palette starts at maximum degree + 1 (or the
+/// highest fixed color, if larger) and grows by one color per failed pass.
+/// The implementation uses 1-based color numbering.
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::Undirected;
+/// use std::collections::HashMap;
+/// use petgraph::algo::wfc_color::wfc_color_with_constraints;
+///
+/// let mut graph = Graph::<(), (), Undirected>::new_undirected();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+///
+/// graph.extend_with_edges(&[
+///     (a, b),
+///     (b, c),
+///     (c, a),
+/// ]);
+///
+/// let mut fixed = HashMap::new();
+/// fixed.insert(a, 1);
+///
+/// let coloring = wfc_color_with_constraints(&graph, &fixed, None).unwrap();
+/// assert_eq!(coloring[&a], 1);
+/// assert_ne!(coloring[&a], coloring[&b]);
+/// ```
+pub fn wfc_color_with_constraints<G>(
+    graph: G,
+    fixed: &HashMap<G::NodeId, usize>,
+    max_colors: Option<usize>,
+) -> Result<HashMap<G::NodeId, usize>, ColoringError>
+where
+    G: IntoNeighbors + NodeCount + NodeIndexable + Visitable,
+    G::NodeId: Eq + Hash + Copy,
+{
+    fn propagate<G>(
+        graph: G,
+        start: G::NodeId,
+        colors: &mut HashMap<G::NodeId, usize>,
+        domains: &mut HashMap<G::NodeId, Vec<usize>>,
+    ) -> bool
+    where
+        G: IntoNeighbors,
+        G::NodeId: Eq + Hash + Copy,
+    {
+        let mut stack = vec![start];
+        while let Some(u) = stack.pop() {
+            if let Some(&color) = colors.get(&u) {
+                for neighbor in graph.neighbors(u) {
+                    if let Some(&existing) = colors.get(&neighbor) {
+                        // Two already-colored adjacent vertices (e.g. two
+                        // separately-propagated fixed vertices) must still
+                        // disagree, or this is a contradiction.
+                        if existing == color {
+                            return false;
+                        }
+                        continue;
+                    }
+                    if let Some(domain) = domains.get_mut(&neighbor) {
+                        if domain.contains(&color) {
+                            domain.retain(|&c| c != color);
+                            match domain.len() {
+                                0 => return false,
+                                1 => {
+                                    colors.insert(neighbor, domain[0]);
+                                    stack.push(neighbor);
+                                }
+                                _ => {}
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        true
+    }
+
+    // Two adjacent fixed vertices sharing a color can never be completed.
+    for (&node, &color) in fixed {
+        for neighbor in graph.neighbors(node) {
+            if fixed.get(&neighbor) == Some(&color) {
+                return Err(ColoringError::Infeasible);
+            }
+        }
+    }
+
+    let nodes: Vec<G::NodeId> = (0..graph.node_bound()).map(|i| graph.from_index(i)).collect();
+    let widest_fixed = fixed.values().copied().max().unwrap_or(0);
+
+    let max_degree = (0..graph.node_bound())
+        .map(|i| graph.from_index(i))
+        .map(|n| graph.neighbors(n).count())
+        .max()
+        .unwrap_or(0);
+
+    let mut budget = max_colors.unwrap_or((max_degree + 1).max(widest_fixed));
+
+    loop {
+        if budget < widest_fixed {
+            return Err(ColoringError::Infeasible);
+        }
+
+        let mut colors: HashMap<G::NodeId, usize> = fixed.clone();
+        let mut domains: HashMap<G::NodeId, Vec<usize>> = nodes
+            .iter()
+            .map(|&n| {
+                let domain = match fixed.get(&n) {
+                    Some(&c) => vec![c],
+                    None => (1..=budget).collect(),
+                };
+                (n, domain)
+            })
+            .collect();
+
+        let mut ok = fixed
+            .keys()
+            .all(|&node| propagate(&graph, node, &mut colors, &mut domains));
+
+        while ok && colors.len() < nodes.len() {
+            let next = nodes
+                .iter()
+                .copied()
+                .filter(|n| !colors.contains_key(n))
+                .min_by_key(|n| domains[n].len());
+
+            match next {
+                Some(v) if !domains[&v].is_empty() => {
+                    let color = domains[&v][0];
+                    colors.insert(v, color);
+                    ok = propagate(&graph, v, &mut colors, &mut domains);
+                }
+                _ => ok = false,
+            }
+        }
+
+        if ok {
+            return Ok(colors);
+        }
+
+        match max_colors {
+            Some(_) => return Err(ColoringError::Infeasible),
+            None => budget += 1,
+        }
+    }
+}
+
+/// Cooperative cancellation and progress reporting for [`wfc_color_with_control`].
+pub struct ColoringControl<'a> {
+    /// Checked before each collapse step and at every restart; the
+    /// coloring aborts as soon as this returns `true`.
+    pub should_cancel: &'a dyn Fn() -> bool,
+    /// Called after each collapse step (and the propagation it triggers)
+    /// with `(colored, total)`, so callers can track progress on large
+    /// graphs.
+    pub progress: Option<&'a dyn Fn(usize, usize)>,
+}
+
+/// Returned by [`wfc_color_with_control`] when `should_cancel` reports
+/// `true`, carrying whatever partial coloring had been produced so far.
+#[derive(Debug, Clone)]
+pub struct Cancelled<N> {
+    /// The coloring as it stood at the moment of cancellation. Not
+    /// necessarily complete or conflict-free.
+    pub partial: HashMap<N, usize>,
+}
+
+/// [Generic] Wave Function Collapse coloring with cancellation and progress.
+///
+/// Behaves like [`wfc_color`], but the restart-on-exhaustion loop checks
+/// `control.should_cancel` before each collapse step and at every restart
+/// so dense or adversarial graphs can be interrupted from another thread
+/// instead of running unbounded. `control.progress`, when set, is called
+/// after each collapse step and its propagation with `(colored, total)`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::Undirected;
+/// use petgraph::algo::wfc_color::{wfc_color_with_control, ColoringControl};
+///
+/// let mut graph = Graph::<(), (), Undirected>::new_undirected();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// graph.extend_with_edges(&[(a, b)]);
+///
+/// let should_cancel = || false;
+/// let control = ColoringControl {
+///     should_cancel: &should_cancel,
+///     progress: None,
+/// };
+/// let coloring = wfc_color_with_control(&graph, &control).unwrap();
+/// assert_ne!(coloring[&a], coloring[&b]);
+/// ```
+pub fn wfc_color_with_control<G>(
+    graph: G,
+    control: &ColoringControl,
+) -> Result<HashMap<G::NodeId, usize>, Cancelled<G::NodeId>>
+where
+    G: IntoNeighbors + NodeCount + NodeIndexable + Visitable,
+    G::NodeId: Eq + Hash + Copy,
+{
+    fn propagate<G>(
+        graph: G,
+        start: G::NodeId,
+        colors: &mut HashMap<G::NodeId, usize>,
+        domains: &mut HashMap<G::NodeId, Vec<usize>>,
+    ) where
+        G: IntoNeighbors,
+        G::NodeId: Eq + Hash + Copy,
+    {
+        let mut stack = vec![start];
+        while let Some(u) = stack.pop() {
+            if let Some(&color) = colors.get(&u) {
+                for neighbor in graph.neighbors(u) {
+                    if !colors.contains_key(&neighbor) {
+                        if let Some(domain) = domains.get_mut(&neighbor) {
+                            if domain.contains(&color) {
+                                domain.retain(|&c| c != color);
+                                if domain.len() == 1 {
+                                    colors.insert(neighbor, domain[0]);
+                                    stack.push(neighbor);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn entropy<N>(node: N, colors: &HashMap<N, usize>, domains: &HashMap<N, Vec<usize>>) -> usize
+    where
+        N: Eq + Hash + Copy,
+    {
+        if colors.contains_key(&node) {
+            0
+        } else {
+            domains.get(&node).map_or(0, |d| d.len())
+        }
+    }
+
+    let total = graph.node_count();
+    let max_degree = (0..graph.node_bound())
+        .map(|i| graph.from_index(i))
+        .map(|n| graph.neighbors(n).count())
+        .max()
+        .unwrap_or(0);
+
+    let mut max_colors = max_degree + 1;
+    let mut colors = HashMap::new();
+    let mut domains = HashMap::new();
+
+    loop {
+        if (control.should_cancel)() {
+            return Err(Cancelled { partial: colors });
+        }
+
+        colors.clear();
+        domains = (0..graph.node_bound())
+            .map(|i| graph.from_index(i))
+            .map(|n| (n, (1..=max_colors).collect()))
+            .collect();
+
+        let start = (0..graph.node_bound())
+            .map(|i| graph.from_index(i))
+            .max_by_key(|&n| graph.neighbors(n).count())
+            .unwrap();
+
+        colors.insert(start, 1);
+        propagate(&graph, start, &mut colors, &mut domains);
+        if let Some(progress) = control.progress {
+            progress(colors.len(), total);
+        }
+
+        let mut restart = false;
+        while colors.len() < total {
+            if (control.should_cancel)() {
+                return Err(Cancelled { partial: colors });
+            }
+
+            let next = (0..graph.node_bound())
+                .map(|i| graph.from_index(i))
+                .filter(|v| !colors.contains_key(v))
+                .min_by_key(|&v| {
+                    let e = entropy(v, &colors, &domains);
+                    if e == 0 {
+                        usize::MAX
+                    } else {
+                        e
+                    }
+                });
+
+            match next {
+                Some(v) => {
+                    if let Some(domain) = domains.get(&v) {
+                        if !domain.is_empty() {
+                            colors.insert(v, domain[0]);
+                            propagate(&graph, v, &mut colors, &mut domains);
+                            if let Some(progress) = control.progress {
+                                progress(colors.len(), total);
+                            }
+                        }
+                    }
+                }
+                None => {
+                    max_colors += 1;
+                    restart = true;
+                    break;
+                }
+            }
+        }
+
+        if !restart {
+            break;
+        }
+    }
+
+    Ok(colors)
+}
+
+/// Checks that `coloring` assigns no two adjacent vertices the same color.
+///
+/// A node with no entry in `coloring` is treated as uncolored rather than
+/// conflicting, so this also accepts partial colorings. Use together with
+/// [`coloring_report`] to additionally see how many colors were used.
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::Undirected;
+/// use petgraph::algo::wfc_color::{wfc_color, is_valid_coloring};
+///
+/// let mut graph = Graph::<(), (), Undirected>::new_undirected();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// graph.extend_with_edges(&[(a, b)]);
+///
+/// let coloring = wfc_color(&graph);
+/// assert!(is_valid_coloring(&graph, &coloring));
+/// ```
+pub fn is_valid_coloring<G>(graph: G, coloring: &HashMap<G::NodeId, usize>) -> bool
+where
+    G: IntoNeighbors + NodeCount + NodeIndexable,
+    G::NodeId: Eq + Hash + Copy,
+{
+    (0..graph.node_bound())
+        .map(|i| graph.from_index(i))
+        .filter_map(|node| coloring.get(&node).map(|&color| (node, color)))
+        .all(|(node, color)| {
+            graph
+                .neighbors(node)
+                .all(|neighbor| coloring.get(&neighbor) != Some(&color))
+        })
+}
+
+/// Chromatic-quality summary produced by [`coloring_report`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColoringReport<N> {
+    /// Number of distinct colors present in the coloring.
+    pub colors_used: usize,
+    /// The highest color value present in the coloring.
+    pub max_color: usize,
+    /// Pairs of adjacent vertices that were assigned the same color.
+    pub conflicts: Vec<(N, N)>,
+}
+
+/// Audits a coloring produced by [`wfc_color`] or any of its siblings.
+///
+/// Where [`is_valid_coloring`] only answers yes or no, this walks the
+/// coloring once and returns the color count, the highest color used, and every conflicting
+/// adjacent pair (each pair reported once, regardless of edge direction).
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::Undirected;
+/// use petgraph::algo::wfc_color::{wfc_color, coloring_report};
+///
+/// let mut graph = Graph::<(), (), Undirected>::new_undirected();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// graph.extend_with_edges(&[(a, b)]);
+///
+/// let coloring = wfc_color(&graph);
+/// let report = coloring_report(&graph, &coloring);
+/// assert!(report.conflicts.is_empty());
+/// ```
+pub fn coloring_report<G>(graph: G, coloring: &HashMap<G::NodeId, usize>) -> ColoringReport<G::NodeId>
+where
+    G: IntoNeighbors + NodeCount + NodeIndexable,
+    G::NodeId: Eq + Hash + Copy,
+{
+    let mut used: Vec<usize> = coloring.values().copied().collect();
+    used.sort_unstable();
+    used.dedup();
+
+    let max_color = coloring.values().copied().max().unwrap_or(0);
+
+    // Track conflicts by index pair, rather than requiring G::NodeId: Ord,
+    // so each adjacent conflicting pair is only reported once.
+    let mut seen_pairs: Vec<(usize, usize)> = Vec::new();
+    let mut conflicts = Vec::new();
+    for (&node, &color) in coloring {
+        for neighbor in graph.neighbors(node) {
+            if coloring.get(&neighbor) == Some(&color) {
+                let (i, j) = (graph.to_index(node), graph.to_index(neighbor));
+                let key = if i < j { (i, j) } else { (j, i) };
+                if !seen_pairs.contains(&key) {
+                    seen_pairs.push(key);
+                    conflicts.push((node, neighbor));
+                }
+            }
+        }
+    }
+
+    ColoringReport {
+        colors_used: used.len(),
+        max_color,
+        conflicts,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Graph;
+    use crate::Undirected;
+
+    #[test]
+    fn dsatur_tie_break_uses_uncolored_neighbor_degree() {
+        // After `z1` is colored, `w` and `x` tie on both saturation degree
+        // and raw degree. Their *uncolored*-neighbor degree differs (w: 3
+        // via x/y1/y2, x: 1 via w only, since x's other edges are
+        // multi-edges to the now-colored `z1`) and must decide the tie,
+        // which in turn decides the exact colors each one gets.
+        let mut graph = Graph::<(), (), Undirected>::new_undirected();
+        let w = graph.add_node(());
+        let x = graph.add_node(());
+        let y1 = graph.add_node(());
+        let y2 = graph.add_node(());
+        let z1 = graph.add_node(());
+
+        graph.add_edge(w, x, ());
+        graph.add_edge(w, y1, ());
+        graph.add_edge(w, y2, ());
+        graph.add_edge(w, z1, ());
+        graph.add_edge(z1, x, ());
+        graph.add_edge(z1, x, ());
+        graph.add_edge(z1, x, ());
+
+        let coloring = dsatur_color(&graph);
+
+        assert_eq!(coloring[&z1], 1);
+        assert_eq!(coloring[&w], 2);
+        assert_eq!(coloring[&x], 3);
+        assert!(is_valid_coloring(&graph, &coloring));
+    }
+
+    #[test]
+    fn control_cancels_promptly_with_partial_coloring() {
+        use std::cell::Cell;
+
+        // A path of 6 vertices needs several collapse steps; cancelling
+        // after a handful of `should_cancel` checks must abort before the
+        // whole graph is colored.
+        let mut graph = Graph::<(), (), Undirected>::new_undirected();
+        let nodes: Vec<_> = (0..6).map(|_| graph.add_node(())).collect();
+        for pair in nodes.windows(2) {
+            graph.add_edge(pair[0], pair[1], ());
+        }
+
+        let calls = Cell::new(0usize);
+        let should_cancel = || {
+            calls.set(calls.get() + 1);
+            calls.get() > 2
+        };
+        let control = ColoringControl {
+            should_cancel: &should_cancel,
+            progress: None,
+        };
+
+        let err = wfc_color_with_control(&graph, &control)
+            .expect_err("should_cancel eventually returns true");
+
+        assert!(!err.partial.is_empty());
+        assert!(err.partial.len() < nodes.len());
+    }
+
+    #[test]
+    fn detects_an_actually_conflicting_coloring() {
+        // a-b edge, both colored 1: a textbook conflict that a coloring
+        // heuristic should never produce but that these helpers must
+        // still catch if it does.
+        let mut graph = Graph::<(), (), Undirected>::new_undirected();
+        let a = graph.add_node(());
+        let b = graph.add_node(());
+        graph.add_edge(a, b, ());
+
+        let mut coloring = HashMap::new();
+        coloring.insert(a, 1);
+        coloring.insert(b, 1);
+
+        assert!(!is_valid_coloring(&graph, &coloring));
+
+        let report = coloring_report(&graph, &coloring);
+        assert!(!report.conflicts.is_empty());
+        assert!(report
+            .conflicts
+            .iter()
+            .any(|&(x, y)| (x, y) == (a, b) || (x, y) == (b, a)));
+    }
+
+    #[test]
+    fn constraints_triangle_with_too_small_budget_is_infeasible() {
+        // a-b-c-a triangle, a and b already pinned to different colors, but
+        // only 2 colors to work with: c is adjacent to both and can't be
+        // completed without repeating one of them.
+        let mut graph = Graph::<(), (), Undirected>::new_undirected();
+        let a = graph.add_node(());
+        let b = graph.add_node(());
+        let c = graph.add_node(());
+        graph.extend_with_edges(&[(a, b), (b, c), (c, a)]);
+
+        let mut fixed = HashMap::new();
+        fixed.insert(a, 1);
+        fixed.insert(b, 2);
+
+        let result = wfc_color_with_constraints(&graph, &fixed, Some(2));
+        assert_eq!(result, Err(ColoringError::Infeasible));
+    }
+
+    #[test]
+    fn seeded_weighting_favors_already_used_colors() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        // No edges: both vertices can legally take either color, so the
+        // second vertex's color is decided purely by `weighted_order`'s
+        // bias toward whatever the first vertex already used.
+        let mut graph = Graph::<(), (), Undirected>::new_undirected();
+        let a = graph.add_node(());
+        let b = graph.add_node(());
+
+        let trials = 2000u64;
+        let matches = (0..trials)
+            .filter(|&seed| {
+                let mut rng = StdRng::seed_from_u64(seed);
+                let coloring = wfc_color_seeded(&graph, &mut rng, 2).unwrap();
+                coloring[&a] == coloring[&b]
+            })
+            .count();
+
+        // Unbiased random picks would match ~50% of the time; the 2:1
+        // weight in favor of the already-used color should push this
+        // noticeably higher.
+        assert!(
+            matches > trials as usize * 11 / 20,
+            "expected a bias toward matching colors, got {matches}/{trials}"
+        );
+    }
+}